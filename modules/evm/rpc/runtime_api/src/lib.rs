@@ -2,7 +2,7 @@
 #![allow(clippy::all)]
 
 use ethereum_types::H160;
-use primitives::evm::{CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::evm::{Account, CallInfo, CreateInfo, EstimateResourcesRequest};
 use sp_runtime::{
 	codec::Codec,
 	traits::{MaybeDisplay, MaybeFromStr},
@@ -10,6 +10,7 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
@@ -35,5 +36,7 @@ sp_api::decl_runtime_apis! {
  		fn get_estimate_resources_request(
 			data: Vec<u8>
 		) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
+
+		fn account_basic(address: H160) -> Account;
 	}
 }
@@ -30,7 +30,7 @@ use primitive_types::{H256, U256};
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	traits::{Convert, DispatchInfoOf, One, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto},
+	traits::{Bounded, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto, Zero},
 	transaction_validity::TransactionValidityError,
 	Either, TransactionOutcome,
 };
@@ -362,6 +362,8 @@ pub mod module {
 		ChargeFeeFailed,
 		/// Contract address conflicts with the system contract
 		ConflictContractAddress,
+		/// Balance too low to transfer
+		BalanceLow,
 	}
 
 	#[pallet::pallet]
@@ -447,7 +449,6 @@ pub mod module {
 
 			#[cfg(not(feature = "with-ethereum-compatibility"))]
 			{
-				use sp_runtime::traits::Zero;
 				let refund_gas = gas_limit.saturating_sub(used_gas);
 				if !refund_gas.is_zero() {
 					// ignore the result to continue. if it fails, just the user will not
@@ -702,10 +703,55 @@ impl<T: Config> Pallet<T> {
 
 		Account {
 			nonce: U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(nonce)),
-			balance: U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(balance)),
+			balance: Self::balance_to_evm(balance),
 		}
 	}
 
+	/// The largest EVM balance that can be represented by the native balance type.
+	///
+	/// Values above this can't be moved without truncation and are rejected.
+	pub fn max_representable_evm_balance() -> U256 {
+		Self::balance_to_evm(BalanceOf::<T>::max_value())
+	}
+
+	/// Convert a native balance to EVM format.
+	pub fn balance_to_evm(balance: BalanceOf<T>) -> U256 {
+		U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(balance))
+	}
+
+	/// Convert an EVM balance to the native balance type.
+	///
+	/// Returns `None` if `value` is above `max_representable_evm_balance`.
+	pub fn evm_to_balance(value: U256) -> Option<BalanceOf<T>> {
+		if value > Self::max_representable_evm_balance() {
+			return None;
+		}
+		Some(value.low_u128().unique_saturated_into())
+	}
+
+	/// Transfer `value` from `source` to `target`.
+	///
+	/// Fails with `BalanceLow` if the free balance of `source` is below
+	/// `value`. `Currency::transfer` is a no-op for self transfer, so the
+	/// locks of `source` are checked here instead.
+	pub fn transfer_value(source: &EvmAddress, target: &EvmAddress, value: BalanceOf<T>) -> DispatchResult {
+		if value.is_zero() {
+			return Ok(());
+		}
+
+		let from = T::AddressMapping::get_account_id(source);
+		let to = T::AddressMapping::get_account_id(target);
+
+		let free = T::Currency::free_balance(&from);
+		ensure!(free >= value, Error::<T>::BalanceLow);
+
+		if from == to {
+			return T::Currency::ensure_can_withdraw(&from, value, WithdrawReasons::TRANSFER, free - value);
+		}
+
+		T::Currency::transfer(&from, &to, value, ExistenceRequirement::AllowDeath)
+	}
+
 	/// Get code hash at given address.
 	pub fn code_hash_at_address(address: &EvmAddress) -> H256 {
 		if let Some(EvmAccountInfo {
@@ -7,7 +7,7 @@ use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use primitives::mocks::MockAddressMapping;
 use primitives::{Amount, BlockNumber, CurrencyId, TokenSymbol};
-use sp_core::{H160, H256};
+use sp_core::{bytes::from_hex, H160, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
@@ -267,3 +267,53 @@ pub fn reserved_balance(address: H160) -> u64 {
 pub fn deploy_free(contract: H160) {
 	let _ = EVM::deploy_free(Origin::signed(CouncilAccount::get()), contract);
 }
+
+pub fn deploy_value_forwarder() -> H160 {
+	// sends the value given in calldata to charlie
+	// PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0
+	// PUSH1 0 CALLDATALOAD
+	// PUSH20 charlie GAS CALL
+	// PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+	let code = from_hex("0x60006000600060006000357310000000000000000000000000000000000000035af160005260206000f3").unwrap();
+	let contract = H160::from_str("2000000000000000000000000000000000000003").unwrap();
+
+	EVM::on_contract_initialization(&contract, &alice(), code).unwrap();
+	#[cfg(not(feature = "with-ethereum-compatibility"))]
+	deploy_free(contract);
+
+	contract
+}
+
+pub fn deploy_self_caller() -> H160 {
+	// sends the value given in calldata to itself
+	// PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0
+	// PUSH1 0 CALLDATALOAD
+	// ADDRESS GAS CALL
+	// PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+	let code = from_hex("0x6000600060006000600035305af160005260206000f3").unwrap();
+	let contract = H160::from_str("2000000000000000000000000000000000000004").unwrap();
+
+	EVM::on_contract_initialization(&contract, &alice(), code).unwrap();
+	#[cfg(not(feature = "with-ethereum-compatibility"))]
+	deploy_free(contract);
+
+	contract
+}
+
+// returns whether the inner CALL succeeded
+pub fn call_with_value(contract: H160, value: U256) -> bool {
+	let mut input = [0u8; 32];
+	value.to_big_endian(&mut input);
+	let result = Runner::<Test>::call(
+		alice(),
+		alice(),
+		contract,
+		input.to_vec(),
+		0,
+		1000000,
+		0,
+		<Test as Config>::config(),
+	)
+	.unwrap();
+	!U256::from(result.output.as_slice()).is_zero()
+}
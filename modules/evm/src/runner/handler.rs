@@ -17,7 +17,7 @@ use primitive_types::{H160, H256, U256};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
 	traits::{One, Saturating, UniqueSaturatedInto, Zero},
-	DispatchError, DispatchResult, SaturatedConversion, TransactionOutcome,
+	ArithmeticError, DispatchError, DispatchResult, TransactionOutcome,
 };
 use sp_std::{cmp::min, convert::Infallible, marker::PhantomData, prelude::*, rc::Rc};
 
@@ -35,6 +35,10 @@ pub struct Handler<'vicinity, 'config, 'meter, T: Config> {
 	_marker: PhantomData<T>,
 }
 
+fn balance_overflow() -> ExitError {
+	ExitError::Other("BalanceOverflow".into())
+}
+
 fn l64(gas: u64) -> u64 {
 	gas - gas / 64
 }
@@ -161,17 +165,14 @@ impl<'vicinity, 'config, T: Config> Handler<'vicinity, 'config, '_, T> {
 		}
 	}
 
-	fn transfer(transfer: Transfer) -> Result<(), ExitError> {
-		let source = T::AddressMapping::get_account_id(&transfer.source);
-		let target = T::AddressMapping::get_account_id(&transfer.target);
+	pub(crate) fn transfer(transfer: Transfer) -> Result<(), ExitError> {
+		let value = Pallet::<T>::evm_to_balance(transfer.value).ok_or_else(balance_overflow)?;
 
-		T::Currency::transfer(
-			&source,
-			&target,
-			transfer.value.saturated_into::<u128>().unique_saturated_into(),
-			ExistenceRequirement::AllowDeath,
-		)
-		.map_err(|_| ExitError::OutOfGas)
+		Pallet::<T>::transfer_value(&transfer.source, &transfer.target, value).map_err(|e| match e {
+			DispatchError::Arithmetic(ArithmeticError::Overflow) => balance_overflow(),
+			e if e == Error::<T>::BalanceLow.into() => ExitError::OutOfFund,
+			e => ExitError::Other(Into::<&'static str>::into(e).into()),
+		})
 	}
 
 	pub fn nonce(address: H160) -> U256 {
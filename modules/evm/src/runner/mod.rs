@@ -1,15 +1,15 @@
 pub mod handler;
 pub mod storage_meter;
 
-use crate::{AddressMapping, BalanceOf, CallInfo, Config, CreateInfo, Error, Pallet, Vicinity};
+use crate::{BalanceOf, CallInfo, Config, CreateInfo, Error, Pallet, Vicinity};
 use evm::{CreateScheme, ExitError, ExitReason};
 use evm_runtime::Handler as HandlerT;
 use evm_gasometer::{self as gasometer};
-use frame_support::traits::{Currency, ExistenceRequirement, Get};
+use frame_support::traits::Get;
 use handler::Handler;
 use primitive_types::{H160, H256, U256};
 use sha3::{Digest, Keccak256};
-use sp_runtime::{traits::Zero, DispatchError, DispatchResult, SaturatedConversion, TransactionOutcome};
+use sp_runtime::{DispatchError, TransactionOutcome};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 #[derive(Default)]
@@ -69,7 +69,7 @@ impl<T: Config> Runner<T> {
 			false,
 			config,
 			|substate| {
-				if let Err(e) = Self::transfer(source, address, value) {
+				if let Err(e) = Pallet::<T>::transfer_value(&source, &address, value) {
 					return TransactionOutcome::Rollback(Err(e));
 				}
 
@@ -81,7 +81,7 @@ impl<T: Config> Runner<T> {
 				let (reason, out) = substate.execute(
 					source,
 					address,
-					U256::from(value.saturated_into::<u128>()),
+					Pallet::<T>::balance_to_evm(value),
 					init,
 					Vec::new(),
 				);
@@ -135,16 +135,6 @@ impl<T: Config> Runner<T> {
 			},
 		)?
 	}
-
-	fn transfer(source: H160, target: H160, value: BalanceOf<T>) -> DispatchResult {
-		if value.is_zero() {
-			return Ok(());
-		}
-
-		let from = T::AddressMapping::get_account_id(&source);
-		let to = T::AddressMapping::get_account_id(&target);
-		T::Currency::transfer(&from, &to, value, ExistenceRequirement::AllowDeath)
-	}
 }
 
 impl<T: Config> Runner<T> {
@@ -183,7 +173,7 @@ impl<T: Config> Runner<T> {
 		Handler::<T>::inc_nonce(sender);
 
 		Handler::<T>::run_transaction(&vicinity, gas_limit, storage_limit, target, false, config, |substate| {
-			if let Err(e) = Self::transfer(sender, target, value) {
+			if let Err(e) = Pallet::<T>::transfer_value(&sender, &target, value) {
 				return TransactionOutcome::Rollback(Err(e));
 			}
 
@@ -193,8 +183,7 @@ impl<T: Config> Runner<T> {
 				return TransactionOutcome::Rollback(Err(DispatchError::Other("OutOfGas")));
 			}
 
-			let (reason, out) =
-				substate.execute(sender, target, U256::from(value.saturated_into::<u128>()), code, input);
+			let (reason, out) = substate.execute(sender, target, Pallet::<T>::balance_to_evm(value), code, input);
 
 			let call_info = CallInfo {
 				exit_reason: reason.clone(),
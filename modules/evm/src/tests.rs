@@ -1195,3 +1195,370 @@ fn evm_execute_mode_should_work() {
 		assert_eq!(balance(alice()), alice_balance);
 	});
 }
+
+#[test]
+fn genesis_accounts_should_work() {
+	new_test_ext().execute_with(|| {
+		// `MockAddressMapping` is stateless, the runtime mapping is covered by
+		// the runtime integration tests.
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		assert_eq!(balance(bob()), INITIAL_BALANCE);
+
+		assert_eq!(
+			Pallet::<Test>::account_basic(&alice()),
+			Account {
+				nonce: U256::from(1),
+				balance: U256::from(INITIAL_BALANCE),
+			}
+		);
+		assert_eq!(
+			Pallet::<Test>::account_basic(&contract_a()),
+			Account {
+				nonce: U256::from(1),
+				balance: U256::zero(),
+			}
+		);
+		assert_eq!(Pallet::<Test>::account_basic(&charlie()), Account::default());
+	});
+}
+
+#[test]
+fn transfer_should_respect_locks() {
+	use frame_support::traits::LockableCurrency;
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		Balances::set_lock(*b"evm/lock", &alice_account_id, INITIAL_BALANCE - 100, WithdrawReasons::all());
+
+		// only 100 is unlocked
+		assert_eq!(
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				bob(),
+				Vec::new(),
+				101,
+				1000000,
+				0,
+				<Test as Config>::config(),
+			),
+			Err(pallet_balances::Error::<Test>::LiquidityRestrictions.into())
+		);
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		assert_eq!(balance(bob()), INITIAL_BALANCE);
+
+		assert_ok!(Runner::<Test>::call(
+			alice(),
+			alice(),
+			bob(),
+			Vec::new(),
+			100,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+		assert_eq!(balance(alice()), INITIAL_BALANCE - 100);
+		assert_eq!(balance(bob()), INITIAL_BALANCE + 100);
+
+		// value sent from a contract
+		let contract = deploy_value_forwarder();
+		let contract_account_id = <Test as Config>::AddressMapping::get_account_id(&contract);
+		Balances::make_free_balance_be(&contract_account_id, 1000);
+		Balances::set_lock(*b"evm/lock", &contract_account_id, 900, WithdrawReasons::all());
+
+		assert_eq!(
+			Handler::<Test>::transfer(evm::Transfer {
+				source: contract,
+				target: charlie(),
+				value: U256::from(101),
+			}),
+			Err(ExitError::Other("LiquidityRestrictions".into()))
+		);
+		assert_eq!(
+			Handler::<Test>::transfer(evm::Transfer {
+				source: contract,
+				target: charlie(),
+				value: U256::from(1001),
+			}),
+			Err(ExitError::OutOfFund)
+		);
+		assert!(!call_with_value(contract, U256::from(101)));
+		assert_eq!(balance(contract), 1000);
+		assert_eq!(balance(charlie()), 0);
+
+		assert!(call_with_value(contract, U256::from(100)));
+		assert_eq!(balance(contract), 900);
+		assert_eq!(balance(charlie()), 100);
+	});
+}
+
+#[test]
+fn transfer_should_reject_unrepresentable_value() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy_value_forwarder();
+
+		let max = Pallet::<Test>::max_representable_evm_balance();
+		assert_eq!(max, U256::from(u64::MAX));
+
+		let contract_account_id = <Test as Config>::AddressMapping::get_account_id(&contract);
+		Balances::make_free_balance_be(&contract_account_id, u64::MAX);
+
+		// max + 1 must not be truncated to max
+		assert!(!call_with_value(contract, max + 1));
+		assert_eq!(balance(contract), u64::MAX);
+		assert_eq!(balance(charlie()), 0);
+
+		assert!(call_with_value(contract, max));
+		assert_eq!(balance(contract), 0);
+		assert_eq!(balance(charlie()), u64::MAX);
+	});
+}
+
+#[test]
+fn transfer_full_balance_should_drain_source() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Runner::<Test>::call(
+			alice(),
+			alice(),
+			charlie(),
+			Vec::new(),
+			INITIAL_BALANCE,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+
+		assert_eq!(balance(alice()), 0);
+		assert_eq!(reserved_balance(alice()), 0);
+		assert_eq!(Pallet::<Test>::account_basic(&alice()).balance, U256::zero());
+		assert_eq!(balance(charlie()), INITIAL_BALANCE);
+		assert_eq!(
+			Pallet::<Test>::account_basic(&charlie()).balance,
+			U256::from(INITIAL_BALANCE)
+		);
+	});
+}
+
+#[test]
+fn transfer_from_new_account_in_same_block_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(balance(charlie()), 0);
+
+		// charlie comes into existence by receiving value
+		assert_ok!(Runner::<Test>::call(
+			alice(),
+			alice(),
+			charlie(),
+			Vec::new(),
+			1000,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+		assert_eq!(balance(charlie()), 1000);
+
+		// and spends it within the same block
+		assert_ok!(Runner::<Test>::call(
+			charlie(),
+			charlie(),
+			bob(),
+			Vec::new(),
+			1000,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+		assert_eq!(System::block_number(), 1);
+		assert_eq!(balance(charlie()), 0);
+		assert_eq!(balance(alice()), INITIAL_BALANCE - 1000);
+		assert_eq!(balance(bob()), INITIAL_BALANCE + 1000);
+	});
+}
+
+#[test]
+fn transfer_should_fail_on_target_overflow() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy_value_forwarder();
+
+		let charlie_account_id = <Test as Config>::AddressMapping::get_account_id(&charlie());
+		Balances::make_free_balance_be(&charlie_account_id, u64::MAX - 1);
+
+		// direct call
+		assert_eq!(
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				charlie(),
+				Vec::new(),
+				2,
+				1000000,
+				0,
+				<Test as Config>::config(),
+			),
+			Err(sp_runtime::ArithmeticError::Overflow.into())
+		);
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		assert_eq!(balance(charlie()), u64::MAX - 1);
+
+		// value sent from a contract
+		let contract_account_id = <Test as Config>::AddressMapping::get_account_id(&contract);
+		Balances::make_free_balance_be(&contract_account_id, 2);
+
+		assert!(!call_with_value(contract, U256::from(2)));
+		assert_eq!(balance(contract), 2);
+		assert_eq!(balance(charlie()), u64::MAX - 1);
+	});
+}
+
+#[test]
+fn account_basic_should_reflect_native_balance() {
+	new_test_ext().execute_with(|| {
+		let charlie_account_id = <Test as Config>::AddressMapping::get_account_id(&charlie());
+		let _ = Balances::deposit_creating(&charlie_account_id, 1000);
+
+		// never used from the EVM side
+		assert!(Pallet::<Test>::accounts(charlie()).is_none());
+		assert_eq!(
+			Pallet::<Test>::account_basic(&charlie()),
+			Account {
+				nonce: U256::zero(),
+				balance: U256::from(1000),
+			}
+		);
+	});
+}
+
+#[test]
+fn self_transfer_should_check_balance() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				alice(),
+				Vec::new(),
+				INITIAL_BALANCE + 1,
+				1000000,
+				0,
+				<Test as Config>::config(),
+			),
+			Err(Error::<Test>::BalanceLow.into())
+		);
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+
+		let before = Pallet::<Test>::account_basic(&alice());
+		assert_ok!(Runner::<Test>::call(
+			alice(),
+			alice(),
+			alice(),
+			Vec::new(),
+			INITIAL_BALANCE,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		// only the nonce moves
+		assert_eq!(Pallet::<Test>::account_basic(&alice()).balance, before.balance);
+		assert_eq!(Pallet::<Test>::account_basic(&alice()).nonce, before.nonce + 1);
+	});
+}
+
+#[test]
+fn self_transfer_from_contract_should_check_balance() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy_self_caller();
+		let contract_account_id = <Test as Config>::AddressMapping::get_account_id(&contract);
+		Balances::make_free_balance_be(&contract_account_id, 1000);
+
+		assert_eq!(
+			Handler::<Test>::transfer(evm::Transfer {
+				source: contract,
+				target: contract,
+				value: U256::from(1001),
+			}),
+			Err(ExitError::OutOfFund)
+		);
+
+		assert!(!call_with_value(contract, U256::from(1001)));
+		assert_eq!(balance(contract), 1000);
+	});
+}
+
+#[test]
+fn self_transfer_should_respect_locks() {
+	use frame_support::traits::LockableCurrency;
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Test as Config>::AddressMapping::get_account_id(&alice());
+		Balances::set_lock(*b"evm/lock", &alice_account_id, INITIAL_BALANCE - 100, WithdrawReasons::all());
+
+		assert_eq!(
+			Runner::<Test>::call(
+				alice(),
+				alice(),
+				alice(),
+				Vec::new(),
+				101,
+				1000000,
+				0,
+				<Test as Config>::config(),
+			),
+			Err(pallet_balances::Error::<Test>::LiquidityRestrictions.into())
+		);
+		assert_ok!(Runner::<Test>::call(
+			alice(),
+			alice(),
+			alice(),
+			Vec::new(),
+			100,
+			1000000,
+			0,
+			<Test as Config>::config(),
+		));
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+
+		// value sent from a contract
+		let contract = deploy_self_caller();
+		let contract_account_id = <Test as Config>::AddressMapping::get_account_id(&contract);
+		Balances::make_free_balance_be(&contract_account_id, 1000);
+		Balances::set_lock(*b"evm/lock", &contract_account_id, 900, WithdrawReasons::all());
+
+		assert_eq!(
+			Handler::<Test>::transfer(evm::Transfer {
+				source: contract,
+				target: contract,
+				value: U256::from(101),
+			}),
+			Err(ExitError::Other("LiquidityRestrictions".into()))
+		);
+
+		assert!(!call_with_value(contract, U256::from(101)));
+		assert_eq!(balance(contract), 1000);
+	});
+}
+
+#[test]
+fn balance_conversion_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Pallet::<Test>::balance_to_evm(0), U256::zero());
+		assert_eq!(Pallet::<Test>::balance_to_evm(1000), U256::from(1000));
+		assert_eq!(Pallet::<Test>::balance_to_evm(u64::MAX), U256::from(u64::MAX));
+
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::zero()), Some(0));
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::from(1000)), Some(1000));
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::from(u64::MAX)), Some(u64::MAX));
+
+		// out of range values are never clamped
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::from(u64::MAX) + 1), None);
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::from(u128::MAX) + 1), None);
+		assert_eq!(Pallet::<Test>::evm_to_balance(U256::max_value()), None);
+
+		for balance in [0u64, 1, 1_000_000_000, INITIAL_BALANCE, u64::MAX].iter() {
+			assert_eq!(
+				Pallet::<Test>::evm_to_balance(Pallet::<Test>::balance_to_evm(*balance)),
+				Some(*balance)
+			);
+		}
+	});
+}
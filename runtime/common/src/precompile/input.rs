@@ -115,6 +115,7 @@ where
 
 		let mut balance = [0u8; BALANCE_BYTES];
 		let start = PER_PARAM_BYTES - BALANCE_BYTES;
+		ensure!(param[..start].iter().all(|b| *b == 0), ExitError::Other("BalanceOverflow".into()));
 		balance[..].copy_from_slice(&param[start..]);
 
 		Ok(Balance::from_be_bytes(balance))
@@ -258,6 +259,15 @@ mod tests {
 		raw_input[16..].copy_from_slice(&balance_bytes);
 		let input = TestInput::new(&raw_input[..]);
 		assert_ok!(input.balance_at(0), balance);
+
+		let mut raw_input = [0u8; 32];
+		raw_input[16..].copy_from_slice(&Balance::MAX.to_be_bytes());
+		let input = TestInput::new(&raw_input[..]);
+		assert_ok!(input.balance_at(0), Balance::MAX);
+
+		raw_input[15] = 1;
+		let input = TestInput::new(&raw_input[..]);
+		assert_err!(input.balance_at(0), ExitError::Other("BalanceOverflow".into()));
 	}
 
 	#[test]
@@ -3,7 +3,7 @@ use super::*;
 use crate::precompile::{
 	mock::{
 		alice, bob, get_task_id, new_test_ext, run_to_block, Balances, Event as TestEvent,
-		ScheduleCallPrecompile, System, Test,
+		MultiCurrencyPrecompile, ScheduleCallPrecompile, System, Test,
 		XOR_ERC20_ADDRESS,
 	},
 	schedule_call::TaskInfo,
@@ -290,6 +290,51 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 }
 
 
+#[test]
+fn multicurrency_precompile_transfer_should_reject_unrepresentable_amount() {
+	new_test_ext().execute_with(|| {
+		let context = Context {
+			address: Default::default(),
+			caller: alice(),
+			apparent_value: Default::default(),
+		};
+		let from_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&alice());
+		let to_account = <Test as module_evm::Config>::AddressMapping::get_account_id(&bob());
+		let from_balance = Balances::free_balance(from_account.clone());
+		let to_balance = Balances::free_balance(to_account.clone());
+
+		let transfer = |amount: U256| {
+			let mut input = [0u8; 5 * 32];
+			// action
+			U256::from(2).to_big_endian(&mut input[0 * 32..1 * 32]);
+			// currency_id XOR
+			U256::default().to_big_endian(&mut input[1 * 32..2 * 32]);
+			// from
+			U256::from(alice().as_bytes()).to_big_endian(&mut input[2 * 32..3 * 32]);
+			// to
+			U256::from(bob().as_bytes()).to_big_endian(&mut input[3 * 32..4 * 32]);
+			// amount
+			amount.to_big_endian(&mut input[4 * 32..5 * 32]);
+			MultiCurrencyPrecompile::execute(&input, None, &context)
+		};
+
+		let max = U256::from(Balance::MAX);
+		// max is representable, and only fails on the balance check
+		assert!(transfer(max).is_err());
+		assert_ne!(transfer(max), Err(ExitError::Other("BalanceOverflow".into())));
+
+		assert_eq!(transfer(max + 1), Err(ExitError::Other("BalanceOverflow".into())));
+		// 2^128 + 5 must not be truncated to 5
+		assert_eq!(transfer(max + 1 + U256::from(5)), Err(ExitError::Other("BalanceOverflow".into())));
+		assert_eq!(Balances::free_balance(from_account.clone()), from_balance);
+		assert_eq!(Balances::free_balance(to_account.clone()), to_balance);
+
+		assert_eq!(transfer(U256::from(5)), Ok((ExitSucceed::Returned, vec![], 0)));
+		assert_eq!(Balances::free_balance(from_account), from_balance - 5);
+		assert_eq!(Balances::free_balance(to_account), to_balance + 5);
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
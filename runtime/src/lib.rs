@@ -231,7 +231,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("oracol"),
 	impl_name: create_runtime_str!("oracol"),
 	authoring_version: 1,
-	spec_version: 8,
+	spec_version: 9,
 	impl_version: 8,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 2,
@@ -1213,6 +1213,10 @@ impl_runtime_apis! {
 			request.ok_or(sp_runtime::DispatchError::Other("Invalid parameter extrinsic, not evm Call"))
 		}
 
+		fn account_basic(address: H160) -> module_evm::Account {
+			EVM::account_basic(&address)
+		}
+
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -532,3 +532,57 @@ fn test_evm_module() {
 			}
 		});
 }
+
+#[test]
+fn evm_runtime_api_account_basic_should_work() {
+	use module_evm_rpc_runtime_api::runtime_decl_for_EVMRuntimeRPCApi::EVMRuntimeRPCApi;
+
+	ExtBuilder::default()
+		.balances(vec![(
+			alice_account_id(),
+			CurrencyId::Token(TokenSymbol::XOR),
+			amount(1 * MILLI_XOR),
+		)])
+		.build()
+		.execute_with(|| {
+			let address = EvmAccounts::eth_address(&alice());
+			let account = <Runtime as EVMRuntimeRPCApi<oracol_runtime::Block, Balance>>::account_basic(address);
+
+			assert_eq!(account.balance, sp_core::U256::from(amount(1 * MILLI_XOR)));
+			assert_eq!(account.encode(), EVM::account_basic(&address).encode());
+		});
+}
+
+#[test]
+fn evm_genesis_accounts_should_work() {
+	let address = EvmAccounts::eth_address(&alice());
+
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+
+	let mut accounts = std::collections::BTreeMap::new();
+	accounts.insert(
+		address,
+		module_evm::GenesisAccount {
+			nonce: 1,
+			balance: amount(1 * MILLI_XOR),
+			storage: Default::default(),
+			code: Default::default(),
+		},
+	);
+	module_evm::GenesisConfig::<Runtime> { accounts }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		// `EvmAddressMapping` reads `EvmAccounts::Accounts`, which is empty at genesis,
+		// so the balance is seeded on the `evm:` prefixed account id.
+		assert_eq!(EvmAccounts::accounts(address), None);
+		assert_eq!(Balances::free_balance(alice_account_id()), amount(1 * MILLI_XOR));
+
+		let account = EVM::account_basic(&address);
+		assert_eq!(account.nonce, sp_core::U256::from(1));
+		assert_eq!(account.balance, sp_core::U256::from(amount(1 * MILLI_XOR)));
+	});
+}